@@ -0,0 +1,104 @@
+use time::{Duration, Time};
+use crate::{ChannelName, ChannelValue, Error, Result, Writer};
+
+impl Writer {
+    /// Splits the accumulated samples into consecutive `window`-sized time windows, keyed
+    /// by the `time` channel, and returns one `Writer` per window carrying the same
+    /// channel headers as `self`.
+    ///
+    /// A row's time-of-day wrapping past midnight (i.e. going backwards relative to the
+    /// previous row) is treated as the next day, so a session spanning midnight still
+    /// bins monotonically instead of jumping back to the first window.
+    pub fn split_into_windows(&self, window: Duration) -> Result<Vec<Writer>> {
+        if window <= Duration::ZERO {
+            return Err(Error::InvalidWindow(window));
+        }
+
+        let time_idx = self
+            .channels
+            .iter()
+            .position(|c| c.name == ChannelName::Time)
+            .ok_or(Error::MissingTimeChannel)?;
+
+        let mut windows: Vec<Writer> = Vec::new();
+        let mut last_time: Option<Time> = None;
+        let mut days_elapsed: i64 = 0;
+
+        for sample in &self.samples {
+            let time = match &sample[time_idx] {
+                ChannelValue::Time(time) => *time,
+                _ => return Err(Error::MissingTimeChannel),
+            };
+
+            if let Some(last) = last_time {
+                if time < last {
+                    days_elapsed += 1;
+                }
+            }
+            last_time = Some(time);
+
+            let elapsed = Duration::days(days_elapsed) + (time - Time::MIDNIGHT);
+            let index = (elapsed.whole_nanoseconds() / window.whole_nanoseconds()) as usize;
+
+            while windows.len() <= index {
+                let mut bin = Writer::new();
+                bin.file_creation_time = self.file_creation_time;
+                bin.comment = self.comment.clone();
+                bin.channels = self.channels.clone();
+                windows.push(bin);
+            }
+
+            windows[index].samples.push(sample.clone());
+        }
+
+        Ok(windows)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::testutil::writer_with_times;
+    use super::*;
+
+    #[test]
+    fn splits_samples_into_fixed_windows() {
+        let writer = writer_with_times(&[(0, 0, 0), (0, 0, 30), (0, 1, 0), (0, 1, 59)]);
+
+        let windows = writer.split_into_windows(Duration::minutes(1)).unwrap();
+
+        assert_eq!(windows.len(), 2);
+        assert_eq!(windows[0].samples().len(), 2);
+        assert_eq!(windows[1].samples().len(), 2);
+        assert_eq!(windows[0].channels(), writer.channels());
+    }
+
+    #[test]
+    fn treats_a_backwards_time_jump_as_the_next_day() {
+        let writer = writer_with_times(&[(23, 59, 0), (0, 0, 30)]);
+
+        let windows = writer.split_into_windows(Duration::minutes(1)).unwrap();
+
+        // The second sample rolls over to the next day rather than binning back to window 0.
+        assert_eq!(windows.len(), 1441);
+        assert_eq!(windows[1439].samples().len(), 1);
+        assert_eq!(windows[1440].samples().len(), 1);
+    }
+
+    #[test]
+    fn requires_a_time_channel() {
+        let writer = Writer::new();
+        assert!(matches!(writer.split_into_windows(Duration::minutes(1)), Err(Error::MissingTimeChannel)));
+    }
+
+    #[test]
+    fn rejects_a_zero_window() {
+        let writer = writer_with_times(&[(0, 0, 0)]);
+        assert!(matches!(writer.split_into_windows(Duration::ZERO), Err(Error::InvalidWindow(_))));
+    }
+
+    #[test]
+    fn rejects_a_negative_window() {
+        let writer = writer_with_times(&[(0, 0, 0)]);
+        assert!(matches!(writer.split_into_windows(-Duration::minutes(1)), Err(Error::InvalidWindow(_))));
+    }
+}
@@ -0,0 +1,122 @@
+use std::fs::File;
+use std::io::BufReader;
+use std::path::Path;
+
+use flate2::read::GzDecoder;
+use flate2::write::GzEncoder;
+use flate2::Compression as GzLevel;
+
+use crate::{Error, Reader, Result, Writer};
+
+/// Whether a `.vbo` file on disk is plain text or gzip-compressed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Compression {
+    None,
+    Gzip,
+}
+
+impl Compression {
+    /// Infers the compression scheme from `path`'s extension, defaulting to `None`.
+    pub fn from_path(path: &Path) -> Self {
+        match path.extension() {
+            Some(ext) if ext == "gz" => Compression::Gzip,
+            _ => Compression::None,
+        }
+    }
+}
+
+impl Writer {
+    /// Writes this document to `path`, transparently gzip-compressing it when
+    /// `compression` is [`Compression::Gzip`]. The plain `write_to<W: Write>` path is
+    /// unaffected.
+    pub fn write_to_path(&self, path: &Path, compression: Compression) -> Result<()> {
+        let mut file = File::create(path)?;
+
+        match compression {
+            Compression::None => self.write_to(&mut file),
+            Compression::Gzip => {
+                let mut encoder = GzEncoder::new(file, GzLevel::default());
+                self.write_to(&mut encoder)?;
+                encoder.finish().map_err(Error::CompressionError)?;
+                Ok(())
+            }
+        }
+    }
+}
+
+impl Reader {
+    /// Reads a document from `path`, transparently gzip-decompressing it when
+    /// `compression` is [`Compression::Gzip`]. The tokenizer is fed the decompressed
+    /// stream, so the reader never has to know the file was compressed.
+    pub fn read_from_path(path: &Path, compression: Compression) -> Result<Writer> {
+        let file = File::open(path)?;
+
+        match compression {
+            Compression::None => Reader::read_from(BufReader::new(file)),
+            Compression::Gzip => Reader::read_from(BufReader::new(GzDecoder::new(file))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Channel, ChannelName, ChannelValue};
+    use super::*;
+
+    struct TempPath(std::path::PathBuf);
+
+    impl Drop for TempPath {
+        fn drop(&mut self) {
+            let _ = std::fs::remove_file(&self.0);
+        }
+    }
+
+    fn temp_path(name: &str) -> TempPath {
+        let mut path = std::env::temp_dir();
+        path.push(format!("vbo-compression-test-{}-{}", std::process::id(), name));
+        TempPath(path)
+    }
+
+    #[test]
+    fn compression_from_path_extension() {
+        assert_eq!(Compression::from_path(Path::new("log.vbo")), Compression::None);
+        assert_eq!(Compression::from_path(Path::new("log.vbo.gz")), Compression::Gzip);
+    }
+
+    #[test]
+    fn writes_and_reads_back_a_gzip_file() {
+        let path = temp_path("roundtrip.vbo.gz");
+
+        let mut writer = Writer::new();
+        writer.add_channel(Channel::new(ChannelName::Satellites, None)).unwrap();
+        writer.add_samples(vec![ChannelValue::Satellites(9)]);
+
+        writer.write_to_path(&path.0, Compression::Gzip).unwrap();
+
+        // The file on disk is actually gzip-compressed, not plain text.
+        assert_ne!(std::fs::read(&path.0).unwrap(), {
+            let mut plain = Vec::new();
+            writer.write_to(&mut plain).unwrap();
+            plain
+        });
+
+        let read = Reader::read_from_path(&path.0, Compression::Gzip).unwrap();
+        assert_eq!(read.channels(), writer.channels());
+        assert_eq!(read.samples(), writer.samples());
+    }
+
+    #[test]
+    fn writes_and_reads_back_a_plain_file() {
+        let path = temp_path("roundtrip.vbo");
+
+        let mut writer = Writer::new();
+        writer.add_channel(Channel::new(ChannelName::Satellites, None)).unwrap();
+        writer.add_samples(vec![ChannelValue::Satellites(9)]);
+
+        writer.write_to_path(&path.0, Compression::None).unwrap();
+
+        let read = Reader::read_from_path(&path.0, Compression::None).unwrap();
+        assert_eq!(read.channels(), writer.channels());
+        assert_eq!(read.samples(), writer.samples());
+    }
+}
@@ -7,8 +7,38 @@ pub type Result<T> = std::result::Result<T, Error>;
 pub enum Error {
     IOError(std::io::Error),
     TimeFormatError(time::error::Format),
+    TimeParseError(time::error::Parse),
     DuplicateChannel(ChannelName),
 
+    /// A `[section]` header that the reader doesn't know how to parse.
+    UnexpectedSection(String),
+
+    /// A data field that couldn't be parsed into the type its column expects.
+    MalformedData(String),
+
+    /// A `[column names]` entry that doesn't match any channel declared in `[header]`.
+    UnknownColumn(ChannelName),
+
+    /// An operation keyed off the `time` channel was attempted on a document that
+    /// doesn't have one.
+    MissingTimeChannel,
+
+    /// A `Merge::merge` row didn't carry a value for a channel the merged document
+    /// expects, because the row's source document never declared that channel.
+    IncompatibleChannelLayout(ChannelName),
+
+    /// `Writer::split_into_windows` was asked to bin samples into a non-positive window,
+    /// which can't be turned into a meaningful sequence of windows.
+    InvalidWindow(time::Duration),
+
+    /// Finishing the gzip stream in `Writer::write_to_path` failed. Errors while writing
+    /// through the encoder or reading through the decoder surface as `IOError`.
+    #[cfg(feature = "gzip")]
+    CompressionError(std::io::Error),
+
+    /// A JSON encode/decode failure from `Writer::to_json`.
+    #[cfg(feature = "serde")]
+    JsonError(serde_json::Error),
 }
 
 impl fmt::Display for Error {
@@ -16,7 +46,18 @@ impl fmt::Display for Error {
         match self {
             Error::IOError(e) => write!(f, "IOError({})", e),
             Error::TimeFormatError(e) => write!(f, "TimeFormatError({})", e),
+            Error::TimeParseError(e) => write!(f, "TimeParseError({})", e),
             Error::DuplicateChannel(n) => write!(f, "DuplicateChannel({:?})", n),
+            Error::UnexpectedSection(s) => write!(f, "UnexpectedSection({:?})", s),
+            Error::MalformedData(s) => write!(f, "MalformedData({:?})", s),
+            Error::UnknownColumn(n) => write!(f, "UnknownColumn({:?})", n),
+            Error::MissingTimeChannel => write!(f, "MissingTimeChannel"),
+            Error::IncompatibleChannelLayout(n) => write!(f, "IncompatibleChannelLayout({:?})", n),
+            Error::InvalidWindow(d) => write!(f, "InvalidWindow({:?})", d),
+            #[cfg(feature = "gzip")]
+            Error::CompressionError(e) => write!(f, "CompressionError({})", e),
+            #[cfg(feature = "serde")]
+            Error::JsonError(e) => write!(f, "JsonError({})", e),
         }
     }
 }
@@ -31,3 +72,14 @@ impl From<time::error::Format> for Error {
         Error::TimeFormatError(e)
     }
 }
+impl From<time::error::Parse> for Error {
+    fn from(e: time::error::Parse) -> Self {
+        Error::TimeParseError(e)
+    }
+}
+#[cfg(feature = "serde")]
+impl From<serde_json::Error> for Error {
+    fn from(e: serde_json::Error) -> Self {
+        Error::JsonError(e)
+    }
+}
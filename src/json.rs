@@ -0,0 +1,32 @@
+use time::format_description::well_known::Rfc3339;
+use crate::{Channel, ChannelValue, Result, Writer};
+
+/// JSON shape emitted by [`Writer::to_json`]: the file-creation time, comment, channel
+/// definitions and full sample matrix, ready for tools that don't want to reimplement
+/// the textual `.vbo` format.
+#[derive(serde::Serialize)]
+struct Document<'a> {
+    file_creation_time: Option<String>,
+    comment: Option<&'a str>,
+    channels: &'a [Channel],
+    samples: &'a [Vec<ChannelValue>],
+}
+
+impl Writer {
+    /// Serializes the accumulated channels and samples to JSON.
+    pub fn to_json(&self) -> Result<String> {
+        let file_creation_time = self
+            .file_creation_time
+            .map(|t| t.format(&Rfc3339))
+            .transpose()?;
+
+        let document = Document {
+            file_creation_time,
+            comment: self.comment.as_deref(),
+            channels: &self.channels,
+            samples: &self.samples,
+        };
+
+        Ok(serde_json::to_string(&document)?)
+    }
+}
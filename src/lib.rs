@@ -1,9 +1,24 @@
 mod error;
 mod writer;
 mod types;
+mod parser;
+mod reader;
+mod merge;
+mod binning;
+mod timestamps;
+#[cfg(feature = "gzip")]
+mod compression;
+#[cfg(feature = "serde")]
+mod json;
+#[cfg(test)]
+mod testutil;
 
 pub use error::*;
 pub use writer::*;
 pub use types::*;
+pub use reader::*;
+pub use merge::*;
+#[cfg(feature = "gzip")]
+pub use compression::*;
 
 pub use dms_coordinates::DMS;
@@ -0,0 +1,204 @@
+use crate::{Channel, ChannelName, ChannelValue, Error, Result, Writer};
+
+/// Concatenates two documents of the same kind into one, the way [`Writer`] and the
+/// reader share their shape so a merged log is still a valid `.vbo`.
+pub trait Merge {
+    /// Merges `other`'s channels and samples into `self`, reordering the combined
+    /// samples by their `time` channel and dropping exact duplicate rows.
+    fn merge(self, other: Self) -> Result<Self>
+    where
+        Self: Sized;
+}
+
+impl Merge for Writer {
+    fn merge(mut self, other: Self) -> Result<Self> {
+        let self_channels = self.channels.clone();
+        let other_channels = other.channels.clone();
+
+        for channel in other.channels {
+            match self.channels.iter().find(|c| c.name == channel.name) {
+                Some(existing) if existing.unit != channel.unit => {
+                    return Err(Error::DuplicateChannel(channel.name));
+                }
+                Some(_) => {}
+                None => self.channels.push(channel),
+            }
+        }
+
+        let mut samples = remap_samples(&self_channels, &self.channels, self.samples)?;
+        samples.extend(remap_samples(&other_channels, &self.channels, other.samples)?);
+        self.samples = samples;
+
+        let time_idx = self
+            .channels
+            .iter()
+            .position(|c| c.name == ChannelName::Time)
+            .ok_or(Error::MissingTimeChannel)?;
+
+        self.samples.sort_by(|a, b| {
+            let time_cmp = match (&a[time_idx], &b[time_idx]) {
+                (ChannelValue::Time(t1), ChannelValue::Time(t2)) => t1.cmp(t2),
+                _ => std::cmp::Ordering::Equal,
+            };
+            time_cmp.then_with(|| row_sort_key(a).cmp(&row_sort_key(b)))
+        });
+        self.samples.dedup();
+
+        Ok(self)
+    }
+}
+
+/// Canonical string form of a whole row, used only to break ties between rows that
+/// share a timestamp. A sort keyed on the `time` column alone is stable, so a third,
+/// distinct row at the same timestamp can land between two exact duplicates and
+/// survive the following `dedup` (which only removes *consecutive* duplicates); this
+/// secondary key forces identical rows to sort adjacent to each other regardless of
+/// how other same-timestamp rows were interleaved beforehand.
+fn row_sort_key(row: &[ChannelValue]) -> String {
+    row.iter().map(ChannelValue::to_string).collect::<Vec<_>>().join("\u{0}")
+}
+
+/// Reorders `samples`, whose columns are laid out per `from`, into `to`'s column order,
+/// so a document's rows stay aligned with its (possibly reordered or widened) merged
+/// `[column names]` list. `to` is expected to be a superset of `from`'s channel names;
+/// a row whose source document never declared one of `to`'s channels can't be
+/// reconciled and is rejected outright, rather than padded with a made-up value.
+fn remap_samples(
+    from: &[Channel],
+    to: &[Channel],
+    samples: Vec<Vec<ChannelValue>>,
+) -> Result<Vec<Vec<ChannelValue>>> {
+    if from.iter().map(|c| &c.name).eq(to.iter().map(|c| &c.name)) {
+        return Ok(samples);
+    }
+
+    samples
+        .into_iter()
+        .map(|row| {
+            to.iter()
+                .map(|channel| {
+                    from.iter()
+                        .position(|c| c.name == channel.name)
+                        .map(|idx| row[idx].clone())
+                        .ok_or_else(|| Error::IncompatibleChannelLayout(channel.name.clone()))
+                })
+                .collect()
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn writer_with(channels: &[ChannelName], rows: &[&[ChannelValue]]) -> Writer {
+        let mut writer = Writer::new();
+        for name in channels {
+            writer.add_channel(Channel::new(name.clone(), None)).unwrap();
+        }
+        for row in rows {
+            writer.add_samples(row.to_vec());
+        }
+        writer
+    }
+
+    fn time(h: u8, m: u8, s: u8) -> ChannelValue {
+        ChannelValue::Time(time::Time::from_hms(h, m, s).unwrap())
+    }
+
+    #[test]
+    fn merge_sorts_by_time_and_dedups() {
+        let a = writer_with(
+            &[ChannelName::Time, ChannelName::Satellites],
+            &[
+                &[time(12, 0, 1), ChannelValue::Satellites(9)],
+                &[time(12, 0, 3), ChannelValue::Satellites(9)],
+            ],
+        );
+        let b = writer_with(
+            &[ChannelName::Time, ChannelName::Satellites],
+            &[
+                &[time(12, 0, 3), ChannelValue::Satellites(9)],
+                &[time(12, 0, 2), ChannelValue::Satellites(9)],
+            ],
+        );
+
+        let merged = a.merge(b).unwrap();
+
+        assert_eq!(
+            merged.samples(),
+            &[
+                vec![time(12, 0, 1), ChannelValue::Satellites(9)],
+                vec![time(12, 0, 2), ChannelValue::Satellites(9)],
+                vec![time(12, 0, 3), ChannelValue::Satellites(9)],
+            ]
+        );
+    }
+
+    #[test]
+    fn merge_drops_a_duplicate_even_when_a_distinct_row_shares_its_timestamp() {
+        // `b`'s only row is an exact duplicate of `a`'s first row, but `a` also has a
+        // second, distinct row at the very same timestamp that a naive sort-by-time
+        // could interleave between the two duplicates.
+        let a = writer_with(
+            &[ChannelName::Time, ChannelName::Satellites],
+            &[
+                &[time(12, 0, 0), ChannelValue::Satellites(1)],
+                &[time(12, 0, 0), ChannelValue::Satellites(2)],
+            ],
+        );
+        let b = writer_with(
+            &[ChannelName::Time, ChannelName::Satellites],
+            &[&[time(12, 0, 0), ChannelValue::Satellites(1)]],
+        );
+
+        let merged = a.merge(b).unwrap();
+
+        assert_eq!(
+            merged.samples(),
+            &[
+                vec![time(12, 0, 0), ChannelValue::Satellites(1)],
+                vec![time(12, 0, 0), ChannelValue::Satellites(2)],
+            ]
+        );
+    }
+
+    #[test]
+    fn merge_reorders_rows_to_the_unified_column_order() {
+        let a = writer_with(
+            &[ChannelName::Time, ChannelName::Satellites],
+            &[&[time(12, 0, 1), ChannelValue::Satellites(5)]],
+        );
+        // `b` declares the same two channels, but in the opposite order.
+        let b = writer_with(
+            &[ChannelName::Satellites, ChannelName::Time],
+            &[&[ChannelValue::Satellites(7), time(12, 0, 2)]],
+        );
+
+        let merged = a.merge(b).unwrap();
+
+        assert_eq!(merged.channels()[0].name, ChannelName::Time);
+        assert_eq!(merged.channels()[1].name, ChannelName::Satellites);
+        assert_eq!(
+            merged.samples(),
+            &[
+                vec![time(12, 0, 1), ChannelValue::Satellites(5)],
+                vec![time(12, 0, 2), ChannelValue::Satellites(7)],
+            ]
+        );
+    }
+
+    #[test]
+    fn merge_rejects_rows_missing_a_channel_the_other_side_introduced() {
+        let a = writer_with(&[ChannelName::Time], &[&[time(12, 0, 1)]]);
+        let b = writer_with(
+            &[ChannelName::Time, ChannelName::Satellites],
+            &[&[time(12, 0, 2), ChannelValue::Satellites(5)]],
+        );
+
+        match a.merge(b) {
+            Err(Error::IncompatibleChannelLayout(ChannelName::Satellites)) => {}
+            other => panic!("expected IncompatibleChannelLayout(Satellites), got {:?}", other.map(|w| w.samples().len())),
+        }
+    }
+}
@@ -1,60 +1,69 @@
-#[derive(Debug, Clone, Hash, PartialEq)]
-enum Token<'a> {
-    SectionHeader(&'a str),
-    Line(&'a str),
-}
-
-fn tokenize<'a>(text: &'a str) -> impl Iterator<Item = Token<'a>> {
-    text.lines()
-        .filter_map(|line| {
-            if line.trim().is_empty() {
-                return None;
-            }
+use std::io::BufRead;
+use crate::{Error, Result};
 
-            Some(if line.starts_with('[') {
-                Token::SectionHeader(line.trim_start_matches('[').trim_end_matches(']'))
-            } else {
-                Token::Line(line)
-            })
-        })
+#[derive(Debug, Clone, Hash, PartialEq)]
+pub(crate) enum Token {
+    SectionHeader(String),
+    Line(String),
 }
-//
-// #[derive(Debug, Clone)]
-// enum Parser<'a> {
-//
-// }
 
+/// Splits a `.vbo` document into a stream of tokens, reading one line at a time so the
+/// whole file never has to be held in memory at once.
+pub(crate) fn tokenize<R: BufRead>(reader: R) -> impl Iterator<Item = Result<Token>> {
+    reader.lines().filter_map(|line| {
+        let line = match line {
+            Ok(line) => line,
+            Err(e) => return Some(Err(Error::from(e))),
+        };
 
+        if line.trim().is_empty() {
+            return None;
+        }
 
+        Some(Ok(if line.starts_with('[') {
+            Token::SectionHeader(line.trim_start_matches('[').trim_end_matches(']').to_string())
+        } else {
+            Token::Line(line)
+        }))
+    })
+}
 
 #[cfg(test)]
 mod tests {
-    use std::io;
-    use crate::parser::{Token, tokenize};
+    use crate::parser::{tokenize, Token};
 
     #[test]
     fn test_tokenize() {
         assert_eq!(
-            tokenize("File created on 07/09/2017 @ 15:58:57").collect::<Vec<_>>(),
-            vec![Token::Line("File created on 07/09/2017 @ 15:58:57")]
+            tokenize("File created on 07/09/2017 @ 15:58:57".as_bytes())
+                .collect::<Result<Vec<_>, _>>()
+                .unwrap(),
+            vec![Token::Line("File created on 07/09/2017 @ 15:58:57".to_string())]
         );
 
         assert_eq!(
-            tokenize("[section header]").collect::<Vec<_>>(),
-            vec![Token::SectionHeader("section header")]
+            tokenize("[section header]".as_bytes())
+                .collect::<Result<Vec<_>, _>>()
+                .unwrap(),
+            vec![Token::SectionHeader("section header".to_string())]
         );
 
         assert_eq!(
-            tokenize("File created on 07/09/2017 at 15:58:57
+            tokenize(
+                "File created on 07/09/2017 at 15:58:57
 
 [header]
 satellites
-time").collect::<Vec<_>>(),
+time"
+                    .as_bytes()
+            )
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap(),
             vec![
-                Token::Line("File created on 07/09/2017 at 15:58:57"),
-                Token::SectionHeader("header"),
-                Token::Line("satellites"),
-                Token::Line("time"),
+                Token::Line("File created on 07/09/2017 at 15:58:57".to_string()),
+                Token::SectionHeader("header".to_string()),
+                Token::Line("satellites".to_string()),
+                Token::Line("time".to_string()),
             ]
         );
     }
@@ -69,15 +78,15 @@ File created on 07/09/2017 at 15:58:57
 satellites
 time
 ";
-        let cursor = io::Cursor::new(data);
+        let cursor = std::io::Cursor::new(data);
 
         assert_eq!(
-            tokenize(cursor).collect::<Vec<_>>(),
+            tokenize(cursor).collect::<Result<Vec<_>, _>>().unwrap(),
             vec![
-                Token::Line("File created on 07/09/2017 at 15:58:57"),
-                Token::SectionHeader("header"),
-                Token::Line("satellites"),
-                Token::Line("time"),
+                Token::Line("File created on 07/09/2017 at 15:58:57".to_string()),
+                Token::SectionHeader("header".to_string()),
+                Token::Line("satellites".to_string()),
+                Token::Line("time".to_string()),
             ]
         );
     }
@@ -0,0 +1,207 @@
+use std::io::BufRead;
+use time::{format_description, PrimitiveDateTime};
+use crate::parser::{tokenize, Token};
+use crate::{Channel, ChannelName, ChannelUnit, ChannelValue, Error, Result, Writer};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Section {
+    Preamble,
+    Header,
+    Comments,
+    ColumnNames,
+    Data,
+}
+
+/// Known multi-word channel names, longest first, so [`parse_channel_line`] can split a
+/// `[header]` line into its name and (optional) trailing unit without guessing.
+const MULTI_WORD_CHANNEL_NAMES: &[&str] = &["long accel", "lat accel"];
+
+/// Parses a full `.vbo` document from a token stream, mirroring [`Writer`] on the way in.
+pub struct Reader;
+
+impl Reader {
+    /// Reads a `.vbo` document from `source`, streaming it line by line rather than
+    /// buffering the whole file.
+    pub fn read_from<R: BufRead>(source: R) -> Result<Writer> {
+        let mut writer = Writer::new();
+        let mut section = Section::Preamble;
+        let mut columns: Vec<ChannelName> = Vec::new();
+        let mut comment_lines: Vec<String> = Vec::new();
+
+        for token in tokenize(source) {
+            match token? {
+                Token::SectionHeader(name) => {
+                    section = match name.as_str() {
+                        "header" => Section::Header,
+                        "comments" => Section::Comments,
+                        "column names" => Section::ColumnNames,
+                        "data" => Section::Data,
+                        _ => return Err(Error::UnexpectedSection(name)),
+                    };
+                }
+                Token::Line(line) => match section {
+                    Section::Preamble => {
+                        writer.set_file_creation_time(parse_preamble(&line)?.assume_utc());
+                    }
+                    Section::Header => {
+                        writer.add_channel(parse_channel_line(&line))?;
+                    }
+                    Section::Comments => {
+                        comment_lines.push(line);
+                    }
+                    Section::ColumnNames => {
+                        columns = parse_column_names(&line, writer.channels())?;
+                    }
+                    Section::Data => {
+                        writer.add_samples(parse_data_line(&line, &columns)?);
+                    }
+                },
+            }
+        }
+
+        if !comment_lines.is_empty() {
+            writer.set_comment(comment_lines.join("\n"));
+        }
+
+        Ok(writer)
+    }
+}
+
+/// Parses the `File created on DD/MM/YYYY at HH:MM:SS` preamble line, the inverse of
+/// `Writer::write_to`'s preamble formatting.
+fn parse_preamble(line: &str) -> Result<PrimitiveDateTime> {
+    let format = format_description::parse(
+        "File created on [day padding:zero]/[month padding:zero repr:numerical]/[year repr:full padding:zero] at [hour padding:zero]:[minute padding:zero]:[second padding:zero]",
+    )
+    .unwrap();
+
+    Ok(PrimitiveDateTime::parse(line, &format)?)
+}
+
+/// Parses a `[header]` line such as `long accel g` into a `Channel`, the inverse of
+/// `Channel`'s `Display` impl.
+fn parse_channel_line(line: &str) -> Channel {
+    let trimmed = line.trim();
+
+    for name in MULTI_WORD_CHANNEL_NAMES {
+        if let Some(rest) = trimmed.strip_prefix(name) {
+            let rest = rest.trim();
+            let unit = if rest.is_empty() { None } else { Some(ChannelUnit::from(rest)) };
+            return Channel::new(ChannelName::from(*name), unit);
+        }
+    }
+
+    let mut parts = trimmed.splitn(2, char::is_whitespace);
+    let name = parts.next().unwrap_or("");
+    let unit = parts.next().map(str::trim).filter(|s| !s.is_empty()).map(ChannelUnit::from);
+
+    Channel::new(ChannelName::from(name), unit)
+}
+
+/// Parses a `[column names]` line, checking that every column refers to a channel that
+/// was already declared in `[header]`.
+///
+/// Like [`parse_channel_line`], this has to recognize the [`MULTI_WORD_CHANNEL_NAMES`]
+/// up front: naively splitting the whole line on whitespace would tear a name such as
+/// `long accel` into two separate (unknown) columns.
+fn parse_column_names(line: &str, channels: &[Channel]) -> Result<Vec<ChannelName>> {
+    let words: Vec<&str> = line.split_whitespace().collect();
+    let mut names = Vec::new();
+    let mut i = 0;
+
+    while i < words.len() {
+        let multi_word = MULTI_WORD_CHANNEL_NAMES.iter().find(|name| {
+            let name_words: Vec<&str> = name.split_whitespace().collect();
+            words[i..].starts_with(&name_words[..])
+        });
+
+        match multi_word {
+            Some(name) => {
+                names.push(ChannelName::from(*name));
+                i += name.split_whitespace().count();
+            }
+            None => {
+                names.push(ChannelName::from(words[i]));
+                i += 1;
+            }
+        }
+    }
+
+    for name in &names {
+        if !channels.iter().any(|c| &c.name == name) {
+            return Err(Error::UnknownColumn(name.clone()));
+        }
+    }
+
+    Ok(names)
+}
+
+/// Parses one `[data]` row, dispatching each whitespace-separated field to the type its
+/// column declares.
+fn parse_data_line(line: &str, columns: &[ChannelName]) -> Result<Vec<ChannelValue>> {
+    let fields: Vec<&str> = line.split_whitespace().collect();
+    if fields.len() != columns.len() {
+        return Err(Error::MalformedData(line.to_string()));
+    }
+
+    columns
+        .iter()
+        .zip(fields)
+        .map(|(name, field)| ChannelValue::parse_for(name, field))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+    use time::{OffsetDateTime, Time};
+    use crate::{Channel, ChannelUnit};
+    use super::*;
+
+    fn round_trip(writer: &Writer) -> Writer {
+        let mut buf = Vec::new();
+        writer.write_to(&mut buf).unwrap();
+        Reader::read_from(Cursor::new(buf)).unwrap()
+    }
+
+    #[test]
+    fn reads_back_a_written_document() {
+        let mut writer = Writer::new();
+        writer.set_file_creation_time(OffsetDateTime::from_unix_timestamp(1641469669).unwrap());
+        writer.set_comment("Cool Comment".to_string());
+        writer.add_channel(Channel::new(ChannelName::Satellites, None)).unwrap();
+        writer.add_channel(Channel::new(ChannelName::Time, None)).unwrap();
+        writer.add_channel(Channel::new(ChannelName::LongAccel, Some(ChannelUnit::G))).unwrap();
+        writer.add_channel(Channel::new(ChannelName::Custom("lean_angle".into()), None)).unwrap();
+
+        writer.add_samples(vec![
+            ChannelValue::Satellites(9),
+            ChannelValue::Time(Time::from_hms_milli(17, 5, 38, 190).unwrap()),
+            ChannelValue::Number(0.123),
+            ChannelValue::Number(-12.5),
+        ]);
+
+        let read = round_trip(&writer);
+
+        assert_eq!(read.channels(), writer.channels());
+        assert_eq!(read.samples(), writer.samples());
+        assert_eq!(read.comment(), Some("Cool Comment"));
+    }
+
+    #[test]
+    fn rejects_a_data_row_with_the_wrong_number_of_fields() {
+        assert!(matches!(
+            parse_data_line("003 170538.19", &[ChannelName::Satellites]),
+            Err(Error::MalformedData(_))
+        ));
+    }
+
+    #[test]
+    fn parses_multi_word_channel_header_lines() {
+        assert_eq!(
+            parse_channel_line("long accel g"),
+            Channel::new(ChannelName::LongAccel, Some(ChannelUnit::G))
+        );
+        assert_eq!(parse_channel_line("satellites"), Channel::new(ChannelName::Satellites, None));
+    }
+}
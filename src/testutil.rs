@@ -0,0 +1,15 @@
+//! Test-only fixtures shared across this crate's `#[cfg(test)]` modules.
+
+use time::Time;
+use crate::{Channel, ChannelName, ChannelValue, Writer};
+
+/// Builds a `Writer` with a single `time` channel and one sample per `(h, m, s)` entry,
+/// the fixture `binning` and `timestamps` both use to exercise time-keyed behavior.
+pub(crate) fn writer_with_times(times: &[(u8, u8, u8)]) -> Writer {
+    let mut writer = Writer::new();
+    writer.add_channel(Channel::new(ChannelName::Time, None)).unwrap();
+    for &(h, m, s) in times {
+        writer.add_samples(vec![ChannelValue::Time(Time::from_hms(h, m, s).unwrap())]);
+    }
+    writer
+}
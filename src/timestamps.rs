@@ -0,0 +1,97 @@
+use time::{OffsetDateTime, Time};
+use crate::{ChannelName, ChannelValue, Error, Result, Writer};
+
+impl Writer {
+    /// Folds each row's `time` channel together with `file_creation_time`'s date into an
+    /// absolute, monotonic `OffsetDateTime` per sample.
+    ///
+    /// A row's time-of-day going backwards relative to the previous row is treated as a
+    /// UTC midnight rollover and advances the date by one day, so sessions spanning
+    /// midnight still produce increasing timestamps.
+    pub fn absolute_timestamps(&self) -> Result<Vec<OffsetDateTime>> {
+        let time_idx = self
+            .channels
+            .iter()
+            .position(|c| c.name == ChannelName::Time)
+            .ok_or(Error::MissingTimeChannel)?;
+
+        let mut date = self.file_creation_time.unwrap_or_else(OffsetDateTime::now_utc).date();
+        let mut last_time: Option<Time> = None;
+        let mut timestamps = Vec::with_capacity(self.samples.len());
+
+        for sample in &self.samples {
+            let time = match &sample[time_idx] {
+                ChannelValue::Time(time) => *time,
+                _ => return Err(Error::MissingTimeChannel),
+            };
+
+            if let Some(last) = last_time {
+                if time < last {
+                    date = date
+                        .next_day()
+                        .ok_or_else(|| Error::MalformedData("date overflow past midnight rollover".to_string()))?;
+                }
+            }
+            last_time = Some(time);
+
+            timestamps.push(date.with_time(time).assume_utc());
+        }
+
+        Ok(timestamps)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::testutil::writer_with_times;
+    use super::*;
+
+    // 06/01/2022 11:47:49 UTC, the same fixture `writer.rs`'s tests use.
+    fn file_creation_time() -> OffsetDateTime {
+        OffsetDateTime::from_unix_timestamp(1641469669).unwrap()
+    }
+
+    fn writer_with_creation_and_times(creation: OffsetDateTime, times: &[(u8, u8, u8)]) -> Writer {
+        let mut writer = writer_with_times(times);
+        writer.set_file_creation_time(creation);
+        writer
+    }
+
+    #[test]
+    fn combines_file_creation_date_with_the_time_channel() {
+        let creation = file_creation_time();
+        let writer = writer_with_creation_and_times(creation, &[(9, 0, 0), (9, 0, 1)]);
+
+        let timestamps = writer.absolute_timestamps().unwrap();
+
+        assert_eq!(
+            timestamps,
+            vec![
+                creation.date().with_time(Time::from_hms(9, 0, 0).unwrap()).assume_utc(),
+                creation.date().with_time(Time::from_hms(9, 0, 1).unwrap()).assume_utc(),
+            ]
+        );
+    }
+
+    #[test]
+    fn rolls_the_date_over_on_a_backwards_time_jump() {
+        let creation = file_creation_time();
+        let writer = writer_with_creation_and_times(creation, &[(23, 59, 0), (0, 0, 30)]);
+
+        let timestamps = writer.absolute_timestamps().unwrap();
+
+        assert_eq!(
+            timestamps,
+            vec![
+                creation.date().with_time(Time::from_hms(23, 59, 0).unwrap()).assume_utc(),
+                creation.date().next_day().unwrap().with_time(Time::from_hms(0, 0, 30).unwrap()).assume_utc(),
+            ]
+        );
+    }
+
+    #[test]
+    fn requires_a_time_channel() {
+        let writer = Writer::new();
+        assert!(matches!(writer.absolute_timestamps(), Err(Error::MissingTimeChannel)));
+    }
+}
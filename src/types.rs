@@ -2,9 +2,11 @@ use log::error;
 use core::fmt;
 use dms_coordinates::DMS;
 use time::{format_description, Time};
+use crate::{Error, Result};
 
 
 #[derive(Debug, Clone, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ChannelName {
     Satellites,
     Time,
@@ -66,6 +68,7 @@ impl<'a> From<&'a str> for ChannelName {
 
 
 #[derive(Debug, Clone, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ChannelUnit {
     Kmh,
     G,
@@ -106,6 +109,7 @@ impl<'a> From<&'a str> for ChannelUnit {
 
 
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Channel {
     pub name: ChannelName,
     pub unit: Option<ChannelUnit>,
@@ -132,6 +136,8 @@ impl fmt::Display for Channel {
 
 
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(into = "ChannelValueRepr", try_from = "ChannelValueRepr"))]
 pub enum ChannelValue {
     ///This is the number of satellites in use in decimal format. 64 is added to this number if the brake trigger input is activated. 128 is added to this number if the VBOX is using a DGPS correction.
     /// e.g. in the file above the sats column shows 137 = 128(DGPS) + 9 sats.
@@ -152,6 +158,113 @@ pub enum ChannelValue {
 
     ///  Height above sea level in meters based on the WGS84 model of the earth used by VBOX GPS engines. e.g.: `+00091.70`
     Height(f64),
+
+    /// A plain decimal sample for a column without a dedicated shape: `long accel`,
+    /// `lat accel`, and any `Custom` channel a particular VBOX logs (e.g. `lean_angle`,
+    /// `fix_type`). e.g.: `0.123`
+    Number(f64),
+}
+
+/// Lossless, serde-friendly shape for [`ChannelValue`]: an ISO time-of-day string instead
+/// of `time::Time`, and decimal degrees plus the original bearing instead of `DMS`.
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+enum ChannelValueRepr {
+    Satellites(u8),
+    Time(String),
+    Coordinates(CoordinatesRepr),
+    Velocity(f64),
+    Heading(f64),
+    Height(f64),
+    Number(f64),
+}
+
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+struct CoordinatesRepr {
+    degrees: f64,
+    bearing: char,
+}
+
+#[cfg(feature = "serde")]
+impl From<ChannelValue> for ChannelValueRepr {
+    fn from(value: ChannelValue) -> Self {
+        match value {
+            ChannelValue::Satellites(n) => ChannelValueRepr::Satellites(n),
+            ChannelValue::Time(t) => ChannelValueRepr::Time(format_time_iso(&t)),
+            ChannelValue::Coordinates(c) => ChannelValueRepr::Coordinates(CoordinatesRepr {
+                degrees: dms_to_decimal_degrees(&c),
+                bearing: c.get_bearing(),
+            }),
+            ChannelValue::Velocity(v) => ChannelValueRepr::Velocity(v),
+            ChannelValue::Heading(v) => ChannelValueRepr::Heading(v),
+            ChannelValue::Height(v) => ChannelValueRepr::Height(v),
+            ChannelValue::Number(v) => ChannelValueRepr::Number(v),
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<ChannelValueRepr> for ChannelValue {
+    type Error = Error;
+
+    fn try_from(repr: ChannelValueRepr) -> Result<Self> {
+        Ok(match repr {
+            ChannelValueRepr::Satellites(n) => ChannelValue::Satellites(n),
+            ChannelValueRepr::Time(s) => ChannelValue::Time(parse_time_iso(&s)?),
+            ChannelValueRepr::Coordinates(c) => {
+                ChannelValue::Coordinates(decimal_degrees_to_dms(c.degrees, c.bearing)?)
+            }
+            ChannelValueRepr::Velocity(v) => ChannelValue::Velocity(v),
+            ChannelValueRepr::Heading(v) => ChannelValue::Heading(v),
+            ChannelValueRepr::Height(v) => ChannelValue::Height(v),
+            ChannelValueRepr::Number(v) => ChannelValue::Number(v),
+        })
+    }
+}
+
+/// ISO time-of-day, e.g. `17:05:38.19`, used for the lossless JSON form of `ChannelValue::Time`.
+#[cfg(feature = "serde")]
+fn format_time_iso(t: &Time) -> String {
+    let format = format_description::parse(
+        "[hour padding:zero]:[minute padding:zero]:[second padding:zero].[subsecond digits:2]",
+    )
+    .unwrap();
+    t.format(&format).expect("a valid Time always formats")
+}
+
+#[cfg(feature = "serde")]
+fn parse_time_iso(s: &str) -> Result<Time> {
+    let format = format_description::parse(
+        "[hour padding:zero]:[minute padding:zero]:[second padding:zero].[subsecond digits:2]",
+    )
+    .unwrap();
+    Ok(Time::parse(s, &format)?)
+}
+
+/// Decimal degrees magnitude of a `DMS`, irrespective of its bearing.
+#[cfg(feature = "serde")]
+fn dms_to_decimal_degrees(dms: &DMS) -> f64 {
+    dms.get_degrees() as f64 + dms.get_minutes() as f64 / 60.0 + dms.get_seconds() / 3600.0
+}
+
+#[cfg(feature = "serde")]
+fn decimal_degrees_to_dms(degrees: f64, bearing: char) -> Result<DMS> {
+    let whole_degrees = degrees.floor();
+    let minutes = (degrees - whole_degrees) * 60.0;
+    let whole_minutes = minutes.floor();
+    let seconds = round5((minutes - whole_minutes) * 60.0);
+
+    DMS::new(whole_degrees as _, whole_minutes as _, seconds, bearing)
+        .map_err(|_| Error::MalformedData(format!("{:.6}", degrees)))
+}
+
+/// Rounds `x` to 5 decimal places, absorbing the floating-point noise the
+/// degrees/minutes/seconds split introduces. The minutes representation carries 6
+/// decimal places, a resolution of 0.00006 seconds, which 5 decimal places of seconds
+/// hold exactly.
+fn round5(x: f64) -> f64 {
+    (x * 100_000.0).round() / 100_000.0
 }
 
 /// Converts a DMS into a minutes only representation used by VBOX
@@ -169,6 +282,59 @@ fn dms_to_minutes(dms: &DMS) -> f64 {
     (deg + min + sec) * nw_multiplier
 }
 
+/// Converts a VBOX minutes value back into a `DMS`, the exact inverse of `dms_to_minutes`.
+///
+/// The sign of `minutes` gives the bearing (positive = `positive_bearing`, e.g. N or W),
+/// and the absolute value is split into degrees/minutes/seconds the same way a clock
+/// splits seconds out of a duration.
+fn minutes_to_dms(minutes: f64, positive_bearing: char, negative_bearing: char) -> Result<DMS> {
+    let bearing = if minutes.is_sign_negative() { negative_bearing } else { positive_bearing };
+    let abs_minutes = minutes.abs();
+
+    let degrees = (abs_minutes / 60.0).floor();
+    let whole_minutes = abs_minutes.floor() % 60.0;
+    let seconds = round5((abs_minutes - abs_minutes.floor()) * 60.0);
+
+    DMS::new(degrees as _, whole_minutes as _, seconds, bearing)
+        .map_err(|_| Error::MalformedData(format!("{:+013.6}", minutes)))
+}
+
+impl ChannelValue {
+    /// Parses a raw `[data]` field into the `ChannelValue` that `name`'s column expects,
+    /// the inverse of `ChannelValue`'s `Display` impl.
+    pub fn parse_for(name: &ChannelName, s: &str) -> Result<ChannelValue> {
+        let s = s.trim();
+        let malformed = || Error::MalformedData(s.to_string());
+
+        match name {
+            ChannelName::Satellites => {
+                Ok(ChannelValue::Satellites(s.parse().map_err(|_| malformed())?))
+            }
+            ChannelName::Time => {
+                let format = format_description::parse(
+                    "[hour padding:zero][minute padding:zero][second padding:zero].[subsecond digits:2]",
+                )
+                .unwrap();
+                Ok(ChannelValue::Time(Time::parse(s, &format)?))
+            }
+            ChannelName::Latitude => {
+                let minutes: f64 = s.parse().map_err(|_| malformed())?;
+                Ok(ChannelValue::Coordinates(minutes_to_dms(minutes, 'N', 'S')?))
+            }
+            ChannelName::Longitude => {
+                let minutes: f64 = s.parse().map_err(|_| malformed())?;
+                Ok(ChannelValue::Coordinates(minutes_to_dms(minutes, 'W', 'E')?))
+            }
+            ChannelName::Velocity => Ok(ChannelValue::Velocity(s.parse().map_err(|_| malformed())?)),
+            ChannelName::Heading => Ok(ChannelValue::Heading(s.parse().map_err(|_| malformed())?)),
+            ChannelName::Height => Ok(ChannelValue::Height(s.parse().map_err(|_| malformed())?)),
+            ChannelName::LongAccel | ChannelName::LatAccel | ChannelName::Custom(_) => {
+                Ok(ChannelValue::Number(s.parse().map_err(|_| malformed())?))
+            }
+        }
+    }
+}
+
 
 impl fmt::Display for ChannelValue {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -186,6 +352,7 @@ impl fmt::Display for ChannelValue {
             ChannelValue::Velocity(v) => write!(f, "{:0>7.3}", v),
             ChannelValue::Heading(v) => write!(f, "{:0>6.2}", v),
             ChannelValue::Height(v) => write!(f, "{:0>+08.2}", v),
+            ChannelValue::Number(v) => write!(f, "{:.3}", v),
         }
     }
 }
@@ -272,10 +439,60 @@ mod tests {
             ("293.00", ChannelValue::Heading(293.00)),
             ("+0155.06", ChannelValue::Height(155.06)),
             ("-0293.00", ChannelValue::Height(-293.00)),
+            ("0.123", ChannelValue::Number(0.123)),
+            ("-1.500", ChannelValue::Number(-1.5)),
         ];
 
         for (formatted, value) in values.into_iter() {
             assert_eq!(formatted, format!("{}", value));
         }
     }
+
+    #[test]
+    fn parse_channel_value_round_trip() {
+        let values = [
+            (ChannelName::Satellites, ChannelValue::Satellites(3)),
+            (ChannelName::Satellites, ChannelValue::Satellites(31)),
+            (ChannelName::Time, ChannelValue::Time(Time::from_hms_milli(17, 05, 38, 190).unwrap())),
+            (ChannelName::Time, ChannelValue::Time(Time::from_hms_milli(17, 23, 17, 590).unwrap())),
+            (ChannelName::Latitude, ChannelValue::Coordinates(DMS::new(51, 59, 5.9838, 'N').unwrap())),
+            (ChannelName::Latitude, ChannelValue::Coordinates(DMS::new(51, 59, 5.9838, 'S').unwrap())),
+            (ChannelName::Longitude, ChannelValue::Coordinates(DMS::new(0, 58, 29.562, 'W').unwrap())),
+            (ChannelName::Longitude, ChannelValue::Coordinates(DMS::new(0, 58, 29.562, 'E').unwrap())),
+            (ChannelName::Velocity, ChannelValue::Velocity(58.493)),
+            (ChannelName::Heading, ChannelValue::Heading(39.40)),
+            (ChannelName::Height, ChannelValue::Height(155.06)),
+            (ChannelName::Height, ChannelValue::Height(-293.00)),
+            (ChannelName::LongAccel, ChannelValue::Number(0.123)),
+            (ChannelName::LatAccel, ChannelValue::Number(-1.5)),
+            (ChannelName::Custom("lean_angle".into()), ChannelValue::Number(12.34)),
+        ];
+
+        for (name, value) in values.into_iter() {
+            let formatted = format!("{}", value);
+            let parsed = ChannelValue::parse_for(&name, &formatted).unwrap();
+            assert_eq!(parsed, value);
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn channel_value_json_round_trip() {
+        let values = [
+            ChannelValue::Satellites(9),
+            ChannelValue::Time(Time::from_hms_milli(17, 05, 38, 190).unwrap()),
+            ChannelValue::Coordinates(DMS::new(51, 59, 5.9838, 'N').unwrap()),
+            ChannelValue::Coordinates(DMS::new(0, 58, 29.562, 'E').unwrap()),
+            ChannelValue::Velocity(58.493),
+            ChannelValue::Heading(39.40),
+            ChannelValue::Height(-293.00),
+            ChannelValue::Number(-1.5),
+        ];
+
+        for value in values {
+            let json = serde_json::to_string(&value).unwrap();
+            let parsed: ChannelValue = serde_json::from_str(&json).unwrap();
+            assert_eq!(parsed, value);
+        }
+    }
 }
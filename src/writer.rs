@@ -3,10 +3,10 @@ use crate::{Result, Error, Channel, ChannelValue};
 use time::{format_description, OffsetDateTime};
 
 pub struct Writer {
-    file_creation_time: Option<OffsetDateTime>,
-    comment: Option<String>,
-    channels: Vec<Channel>,
-    samples: Vec<Vec<ChannelValue>>,
+    pub(crate) file_creation_time: Option<OffsetDateTime>,
+    pub(crate) comment: Option<String>,
+    pub(crate) channels: Vec<Channel>,
+    pub(crate) samples: Vec<Vec<ChannelValue>>,
 }
 
 impl Writer {
@@ -40,6 +40,22 @@ impl Writer {
         self.samples.push(line);
     }
 
+    pub fn file_creation_time(&self) -> Option<OffsetDateTime> {
+        self.file_creation_time
+    }
+
+    pub fn comment(&self) -> Option<&str> {
+        self.comment.as_deref()
+    }
+
+    pub fn channels(&self) -> &[Channel] {
+        &self.channels
+    }
+
+    pub fn samples(&self) -> &[Vec<ChannelValue>] {
+        &self.samples
+    }
+
 
     pub fn write_to<W: Write>(&self, sink: &mut W) -> Result<()> {
         // Write File comment